@@ -4,4 +4,6 @@ use thiserror::Error;
 pub enum Error {
     #[error("Invalid dimensions")]
     InvalidDimensions((usize, usize), (usize, usize)),
+    #[error("Matrix is singular")]
+    Singular,
 }
\ No newline at end of file
@@ -1,4 +1,4 @@
-use std::{ops::{Range, RangeFull, RangeInclusive, RangeFrom, RangeTo, RangeToInclusive, Mul, MulAssign, Add, Sub, Div, Index, IndexMut, AddAssign}, fmt::Display};
+use std::{ops::{Range, RangeFull, RangeInclusive, RangeFrom, RangeTo, RangeToInclusive, Mul, MulAssign, Add, Sub, Div, Neg, Index, IndexMut, AddAssign}, fmt::Display};
 
 pub trait AsIndex {
     fn start(&self) -> Option<usize>;
@@ -75,14 +75,32 @@ impl<T: From<u8> + Mul<Output = T> + MulAssign + Add<Output = T> + AddAssign + S
     }
 }
 
+/// A [`Numerical`] type that also supports negation and division, e.g. LU
+/// decomposition and element-wise division. Kept separate from `Numerical`
+/// so unsigned element types (which have no `Neg`) still get `matmul`,
+/// `matadd` and friends.
+pub trait Signed<T>: Numerical<T> + Div<Output = T> + Neg<Output = T> {}
+
+impl<T: Numerical<T> + Div<Output = T> + Neg<Output = T>> Signed<T> for T {}
+
+/// A [`Signed`] type with well-behaved division, i.e. an actual floating-point
+/// type. LU decomposition and the routines built on it (`determinant`,
+/// `solve`, `inverse`) need this rather than `Signed` alone: over an integral
+/// `T`, `Signed` still type-checks but the pivot division truncates and
+/// silently produces the wrong answer instead of erroring.
+pub trait Float<T>: Signed<T> + PartialOrd {}
+
+impl Float<f32> for f32 {}
+impl Float<f64> for f64 {}
+
 pub trait Matrix<T: Numerical<T>, E>: Mul + Add + Sub + Index<(usize, usize)> + IndexMut<(usize, usize)> + Sized {
     fn new(rows: usize, cols: usize) -> Self;
     fn err(error: E) -> Self;
-    
+
     fn is_ok(&self) -> bool;
     fn is_err(&self) -> bool;
     fn dup(&self) -> Self;
-    
+
     fn get<U: AsIndex>(&self, rows: U, cols: U) -> Self;
 
     fn t(self) -> Self;
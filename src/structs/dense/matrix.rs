@@ -1,6 +1,6 @@
-use std::{ptr::NonNull, ops::{IndexMut, Index, Mul, Add, Sub}, fmt::Display};
+use std::{ptr::NonNull, marker::PhantomData, ops::{IndexMut, Index, Mul, Add, Sub, Neg, AddAssign, SubAssign, MulAssign}, fmt::Display};
 
-pub use crate::helpers::traits::{AsIndex, Numerical, Matrix};
+pub use crate::helpers::traits::{AsIndex, Numerical, Signed, Float, Matrix};
 use crate::helpers::memory::{allocate, deallocate, copymemory};
 use crate::helpers::errors::Error;
 
@@ -16,6 +16,66 @@ pub struct Dense<T: Numerical<T>> {
     pub data: Result<DenseData<T>, Error>
 }
 
+/// A borrowing, read-only window into a parent [`Dense`]'s buffer.
+///
+/// `values` points directly into the parent's allocation at the sub-block's
+/// first element; `row_stride`/`col_stride` mirror the parent's physical
+/// layout (swapped when the parent is a lazy transpose), so element `(i, j)`
+/// lives at `values + i * row_stride + j * col_stride`. No data is copied.
+pub struct DenseSlice<'a, T: Numerical<T>> {
+    rows: usize,
+    cols: usize,
+    row_stride: usize,
+    col_stride: usize,
+    values: NonNull<T>,
+    _marker: PhantomData<&'a T>
+}
+
+/// Like [`DenseSlice`], but allows writing back into the parent's buffer.
+pub struct DenseSliceMut<'a, T: Numerical<T>> {
+    rows: usize,
+    cols: usize,
+    row_stride: usize,
+    col_stride: usize,
+    values: NonNull<T>,
+    _marker: PhantomData<&'a mut T>
+}
+
+/// Common read access to a dense block of `T`, whether owned ([`DenseData`])
+/// or a strided view ([`DenseSlice`]/[`DenseSliceMut`]), so operations like
+/// [`matmul`] and [`matadd`] can run over either without materializing a copy.
+trait DenseView<T: Numerical<T>> {
+    fn dims(&self) -> (usize, usize);
+    fn at(&self, row: usize, col: usize) -> T;
+}
+
+impl<T: Numerical<T>> DenseView<T> for DenseData<T> {
+    fn dims(&self) -> (usize, usize) {
+        (self.rows, self.cols)
+    }
+    fn at(&self, row: usize, col: usize) -> T {
+        self[(row, col)]
+    }
+}
+
+impl<T: Numerical<T>> DenseView<T> for DenseSlice<'_, T> {
+    fn dims(&self) -> (usize, usize) {
+        (self.rows, self.cols)
+    }
+    fn at(&self, row: usize, col: usize) -> T {
+        self[(row, col)]
+    }
+}
+
+impl<T: Numerical<T>> DenseView<T> for DenseSliceMut<'_, T> {
+    fn dims(&self) -> (usize, usize) {
+        (self.rows, self.cols)
+    }
+    fn at(&self, row: usize, col: usize) -> T {
+        self[(row, col)]
+    }
+}
+
 impl<T: Numerical<T>> DenseData<T> {
     pub fn from_values(rows: usize, cols: usize, values: NonNull<T>) -> Self {
         Self {
@@ -51,13 +111,18 @@ impl<T: Numerical<T>> DenseData<T> {
         ((row_start, row_end), (col_start, col_end))
     }
 
-    fn get_data_mut(&self, index: usize) -> &mut T {
-        assert!(index < self.rows * self.cols, "Index out of bounds");
-        unsafe {self.values.as_ptr().add(index).as_mut().unwrap()}
+    /// Physical offset of logical `(row, col)`, honoring `transposed` so the same
+    /// buffer backs both a matrix and its transpose with no data movement.
+    fn offset(&self, row: usize, col: usize) -> usize {
+        if self.transposed {
+            col * self.rows + row
+        } else {
+            row * self.cols + col
+        }
     }
 
-    unsafe fn get_data_mut_unchecked(&self, index: usize) -> &mut T {
-        self.values.as_ptr().add(index).as_mut().unwrap()
+    unsafe fn get_data_mut_unchecked(&mut self, row: usize, col: usize) -> &mut T {
+        self.values.as_ptr().add(self.offset(row, col)).as_mut().unwrap()
     }
 }
 
@@ -67,15 +132,22 @@ impl<T: Numerical<T>> Drop for DenseData<T> {
     }
 }
 
+/// Shared [`Display`] body for any [`DenseView`], so `DenseData`/`DenseSlice`/
+/// `DenseSliceMut` don't each repeat the same nested loop.
+fn fmt_view<T: Numerical<T>>(view: &impl DenseView<T>, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    let (rows, cols) = view.dims();
+    for i in 0..rows {
+        for j in 0..cols {
+            write!(f, "{} ", view.at(i, j))?;
+        }
+        writeln!(f)?;
+    }
+    Ok(())
+}
+
 impl<T: Numerical<T>> Display for DenseData<T> {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        for i in 0..self.rows {
-            for j in 0..self.cols {
-                write!(f, "{} ", self[(i, j)])?;
-            }
-            writeln!(f)?;
-        }
-        Ok(())
+        fmt_view(self, f)
     }
 }
 
@@ -90,17 +162,18 @@ impl<T: Numerical<T>> Display for Dense<T> {
 
 // ---- OPERATIONS ----
 // Mul
-fn matmul<T: Numerical<T>>(lhs: &DenseData<T>, rhs: &DenseData<T>) -> Dense<T> {
-    if lhs.cols != rhs.rows {
-        return Dense::err(Error::InvalidDimensions((0, lhs.cols), (rhs.rows, 0)));
+fn matmul<T: Numerical<T>, A: DenseView<T>, B: DenseView<T>>(lhs: &A, rhs: &B) -> Dense<T> {
+    let (lhs_rows, lhs_cols) = lhs.dims();
+    let (rhs_rows, rhs_cols) = rhs.dims();
+    if lhs_cols != rhs_rows {
+        return Dense::err(Error::InvalidDimensions((0, lhs_cols), (rhs_rows, 0)));
     }
-    let mut result = Dense::new(lhs.rows, rhs.cols);
+    let mut result = Dense::new(lhs_rows, rhs_cols);
     let data = &mut result.data.as_mut().unwrap();
-    for i in 0..lhs.rows {
-        for j in 0..rhs.cols {
-            for k in 0..lhs.cols {
-                unsafe {*data.get_data_mut_unchecked(i * data.cols + j) += 
-                    *lhs.get_data_mut_unchecked(i * lhs.cols + k) * *rhs.get_data_mut_unchecked(k * rhs.cols + j)}
+    for i in 0..lhs_rows {
+        for j in 0..rhs_cols {
+            for k in 0..lhs_cols {
+                unsafe {*data.get_data_mut_unchecked(i, j) += lhs.at(i, k) * rhs.at(k, j)}
             }
         }
     }
@@ -112,8 +185,10 @@ fn pointwisemul<T: Numerical<T>>(lhs: &DenseData<T>, rhs: &DenseData<T>) -> Dens
     }
     let mut result = Dense::new(lhs.rows, lhs.cols);
     let data = &mut result.data.as_mut().unwrap();
-    for i in 0..(lhs.rows * lhs.cols) {
-        unsafe {*data.get_data_mut_unchecked(i) = *lhs.get_data_mut_unchecked(i) * *rhs.get_data_mut_unchecked(i)}
+    for i in 0..lhs.rows {
+        for j in 0..lhs.cols {
+            unsafe {*data.get_data_mut_unchecked(i, j) = lhs[(i, j)] * rhs[(i, j)]}
+        }
     }
     result
 }
@@ -131,14 +206,18 @@ impl<T: Numerical<T>> Mul<Dense<T>> for Dense<T> {
     }
 }
 // Add
-fn matadd<T: Numerical<T>>(lhs: &DenseData<T>, rhs: &DenseData<T>) -> Dense<T> {
-    if lhs.rows != rhs.rows || lhs.cols != rhs.cols {
-        return Dense::err(Error::InvalidDimensions((lhs.rows, lhs.cols), (rhs.rows, rhs.cols)));
+fn matadd<T: Numerical<T>, A: DenseView<T>, B: DenseView<T>>(lhs: &A, rhs: &B) -> Dense<T> {
+    let (lhs_rows, lhs_cols) = lhs.dims();
+    let (rhs_rows, rhs_cols) = rhs.dims();
+    if lhs_rows != rhs_rows || lhs_cols != rhs_cols {
+        return Dense::err(Error::InvalidDimensions((lhs_rows, lhs_cols), (rhs_rows, rhs_cols)));
     }
-    let mut result = Dense::new(lhs.rows, lhs.cols);
+    let mut result = Dense::new(lhs_rows, lhs_cols);
     let data = &mut result.data.as_mut().unwrap();
-    for i in 0..(lhs.rows * lhs.cols) {
-        unsafe {*data.get_data_mut_unchecked(i) = *lhs.get_data_mut_unchecked(i) + *rhs.get_data_mut_unchecked(i)}
+    for i in 0..lhs_rows {
+        for j in 0..lhs_cols {
+            unsafe {*data.get_data_mut_unchecked(i, j) = lhs.at(i, j) + rhs.at(i, j)}
+        }
     }
     result
 }
@@ -158,8 +237,10 @@ fn matsub<T: Numerical<T>>(lhs: &DenseData<T>, rhs: &DenseData<T>) -> Dense<T> {
     }
     let mut result = Dense::new(lhs.rows, lhs.cols);
     let data = &mut result.data.as_mut().unwrap();
-    for i in 0..(lhs.rows * lhs.cols) {
-        unsafe {*data.get_data_mut_unchecked(i) = *lhs.get_data_mut_unchecked(i) - *rhs.get_data_mut_unchecked(i)}
+    for i in 0..lhs.rows {
+        for j in 0..lhs.cols {
+            unsafe {*data.get_data_mut_unchecked(i, j) = lhs[(i, j)] - rhs[(i, j)]}
+        }
     }
     result
 }
@@ -179,14 +260,14 @@ impl<T: Numerical<T>> Index<(usize, usize)> for DenseData<T> {
     fn index(&self, index: (usize, usize)) -> &Self::Output {
         assert!(index.0 < self.rows, "Row index out of bounds");
         assert!(index.1 < self.cols, "Column index out of bounds");
-        unsafe {self.values.as_ptr().add(index.0 * self.cols + index.1).as_ref().unwrap()}
+        unsafe {self.values.as_ptr().add(self.offset(index.0, index.1)).as_ref().unwrap()}
     }
 }
 impl<T: Numerical<T>> IndexMut<(usize, usize)> for DenseData<T> {
     fn index_mut(&mut self, index: (usize, usize)) -> &mut Self::Output {
         assert!(index.0 < self.rows, "Row index out of bounds");
         assert!(index.1 < self.cols, "Column index out of bounds");
-        unsafe {self.values.as_ptr().add(index.0 * self.cols + index.1).as_mut().unwrap()}
+        unsafe {self.values.as_ptr().add(self.offset(index.0, index.1)).as_mut().unwrap()}
     }
 }
 impl<T: Numerical<T>> Index<(usize, usize)> for Dense<T> {
@@ -201,6 +282,69 @@ impl<T: Numerical<T>> IndexMut<(usize, usize)> for Dense<T> {
         &mut self.data.as_mut().expect("Cannot index into an error matrix")[index]
     }
 }
+impl<T: Numerical<T>> Index<(usize, usize)> for DenseSlice<'_, T> {
+    type Output = T;
+
+    fn index(&self, index: (usize, usize)) -> &Self::Output {
+        assert!(index.0 < self.rows, "Row index out of bounds");
+        assert!(index.1 < self.cols, "Column index out of bounds");
+        unsafe {self.values.as_ptr().add(index.0 * self.row_stride + index.1 * self.col_stride).as_ref().unwrap()}
+    }
+}
+impl<T: Numerical<T>> Index<(usize, usize)> for DenseSliceMut<'_, T> {
+    type Output = T;
+
+    fn index(&self, index: (usize, usize)) -> &Self::Output {
+        assert!(index.0 < self.rows, "Row index out of bounds");
+        assert!(index.1 < self.cols, "Column index out of bounds");
+        unsafe {self.values.as_ptr().add(index.0 * self.row_stride + index.1 * self.col_stride).as_ref().unwrap()}
+    }
+}
+impl<T: Numerical<T>> IndexMut<(usize, usize)> for DenseSliceMut<'_, T> {
+    fn index_mut(&mut self, index: (usize, usize)) -> &mut Self::Output {
+        assert!(index.0 < self.rows, "Row index out of bounds");
+        assert!(index.1 < self.cols, "Column index out of bounds");
+        unsafe {self.values.as_ptr().add(index.0 * self.row_stride + index.1 * self.col_stride).as_mut().unwrap()}
+    }
+}
+impl<T: Numerical<T>> Display for DenseSlice<'_, T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        fmt_view(self, f)
+    }
+}
+impl<T: Numerical<T>> Display for DenseSliceMut<'_, T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        fmt_view(self, f)
+    }
+}
+impl<T: Numerical<T>> Mul<&DenseSlice<'_, T>> for &DenseSlice<'_, T> {
+    type Output = Dense<T>;
+
+    fn mul(self, rhs: &DenseSlice<'_, T>) -> Self::Output {
+        matmul(self, rhs)
+    }
+}
+impl<T: Numerical<T>> Add<&DenseSlice<'_, T>> for &DenseSlice<'_, T> {
+    type Output = Dense<T>;
+
+    fn add(self, rhs: &DenseSlice<'_, T>) -> Self::Output {
+        matadd(self, rhs)
+    }
+}
+impl<T: Numerical<T>> Mul<&DenseSliceMut<'_, T>> for &DenseSliceMut<'_, T> {
+    type Output = Dense<T>;
+
+    fn mul(self, rhs: &DenseSliceMut<'_, T>) -> Self::Output {
+        matmul(self, rhs)
+    }
+}
+impl<T: Numerical<T>> Add<&DenseSliceMut<'_, T>> for &DenseSliceMut<'_, T> {
+    type Output = Dense<T>;
+
+    fn add(self, rhs: &DenseSliceMut<'_, T>) -> Self::Output {
+        matadd(self, rhs)
+    }
+}
 impl<T: Numerical<T>> Matrix<T, Error> for Dense<T> {
     fn new(rows: usize, cols: usize) -> Self {
         let data = allocate::<T>(rows, cols);
@@ -226,6 +370,7 @@ impl<T: Numerical<T>> Matrix<T, Error> for Dense<T> {
         let mut result = Self::new(orig_data.rows, orig_data.cols);
         let result_data = &mut result.data.as_mut().unwrap();
         unsafe {copymemory(&orig_data.values, &mut result_data.values, 0..(orig_data.rows * orig_data.cols), 0)};
+        result_data.transposed = orig_data.transposed;
         result
     }
     fn get<U: AsIndex>(&self, rows: U, cols: U) -> Self {
@@ -237,22 +382,27 @@ impl<T: Numerical<T>> Matrix<T, Error> for Dense<T> {
         let mut result = Self::new(row_end - row_start, col_end - col_start);
         let result_data = &mut result.data.as_mut().unwrap();
         for i in row_start..row_end {
-            unsafe {copymemory(&orig_data.values, &mut result_data.values, (i * orig_data.cols + col_start)..(i * orig_data.cols + col_end), 
-                (i - row_start) * result_data.cols)}
+            for j in col_start..col_end {
+                result_data[(i - row_start, j - col_start)] = orig_data[(i, j)];
+            }
         }
         result
     }
     fn scale(mut self, scale: T) -> Self {
         let Ok(data) = &mut self.data else {return self};
-        for i in 0..(data.rows * data.cols) {
-            unsafe {*data.get_data_mut_unchecked(i) *= scale};
+        for i in 0..data.rows {
+            for j in 0..data.cols {
+                unsafe {*data.get_data_mut_unchecked(i, j) *= scale};
+            }
         }
         self
     }
     fn shift(mut self, shift: T) -> Self {
         let Ok(data) = &mut self.data else {return self};
-        for i in 0..(data.rows * data.cols) {
-            unsafe {*data.get_data_mut_unchecked(i) += shift};
+        for i in 0..data.rows {
+            for j in 0..data.cols {
+                unsafe {*data.get_data_mut_unchecked(i, j) += shift};
+            }
         }
         self
     }
@@ -264,17 +414,422 @@ impl<T: Numerical<T>> Matrix<T, Error> for Dense<T> {
     fn t(mut self) -> Self {
         let Ok(data) = &mut self.data else {return self};
         data.transposed = !data.transposed;
-        for i in 0..data.rows - 1 {
-            for j in i + 1..data.cols {
-                unsafe {std::mem::swap(data.get_data_mut_unchecked(i * data.cols + j), 
-                    data.get_data_mut_unchecked(j * data.cols + i))};
+        std::mem::swap(&mut data.rows, &mut data.cols);
+        self
+    }
+}
+
+impl<T: Signed<T>> Dense<T> {
+    /// Element-wise division by a scalar.
+    pub fn div_scalar(mut self, d: T) -> Self {
+        let Ok(data) = &mut self.data else {return self};
+        for i in 0..data.rows {
+            for j in 0..data.cols {
+                unsafe {
+                    let value = *data.get_data_mut_unchecked(i, j);
+                    *data.get_data_mut_unchecked(i, j) = value / d;
+                }
             }
         }
-        std::mem::swap(&mut data.rows, &mut data.cols);
         self
     }
 }
 
+impl<T: Signed<T>> Neg for Dense<T> {
+    type Output = Dense<T>;
+
+    fn neg(mut self) -> Self::Output {
+        let Ok(data) = &mut self.data else {return self};
+        for i in 0..data.rows {
+            for j in 0..data.cols {
+                unsafe {*data.get_data_mut_unchecked(i, j) = -*data.get_data_mut_unchecked(i, j)};
+            }
+        }
+        self
+    }
+}
+
+impl<T: Numerical<T>> AddAssign<Dense<T>> for Dense<T> {
+    fn add_assign(&mut self, rhs: Self) {
+        let rhs_data = match rhs.data {
+            Ok(data) => data,
+            Err(err) => {
+                self.data = Err(err);
+                return;
+            }
+        };
+        let Ok(lhs) = &mut self.data else {return};
+        if lhs.rows != rhs_data.rows || lhs.cols != rhs_data.cols {
+            let dims = Error::InvalidDimensions((lhs.rows, lhs.cols), (rhs_data.rows, rhs_data.cols));
+            self.data = Err(dims);
+            return;
+        }
+        for i in 0..lhs.rows {
+            for j in 0..lhs.cols {
+                unsafe {*lhs.get_data_mut_unchecked(i, j) += rhs_data[(i, j)]};
+            }
+        }
+    }
+}
+
+impl<T: Numerical<T>> SubAssign<Dense<T>> for Dense<T> {
+    fn sub_assign(&mut self, rhs: Self) {
+        let rhs_data = match rhs.data {
+            Ok(data) => data,
+            Err(err) => {
+                self.data = Err(err);
+                return;
+            }
+        };
+        let Ok(lhs) = &mut self.data else {return};
+        if lhs.rows != rhs_data.rows || lhs.cols != rhs_data.cols {
+            let dims = Error::InvalidDimensions((lhs.rows, lhs.cols), (rhs_data.rows, rhs_data.cols));
+            self.data = Err(dims);
+            return;
+        }
+        for i in 0..lhs.rows {
+            for j in 0..lhs.cols {
+                unsafe {
+                    let value = *lhs.get_data_mut_unchecked(i, j);
+                    *lhs.get_data_mut_unchecked(i, j) = value - rhs_data[(i, j)];
+                }
+            }
+        }
+    }
+}
+
+impl<T: Numerical<T>> MulAssign<Dense<T>> for Dense<T> {
+    fn mul_assign(&mut self, rhs: Self) {
+        let rhs_data = match rhs.data {
+            Ok(data) => data,
+            Err(err) => {
+                self.data = Err(err);
+                return;
+            }
+        };
+        let lhs_data = match &self.data {
+            Ok(data) => data,
+            Err(_) => return
+        };
+        let result = if lhs_data.pointwise || rhs_data.pointwise {
+            pointwisemul(lhs_data, &rhs_data)
+        } else {
+            matmul(lhs_data, &rhs_data)
+        };
+        self.data = result.data;
+    }
+}
+
+/// Row-major `(row, col)` pairs over a `rows x cols` logical shape.
+pub struct Indices {
+    rows: usize,
+    cols: usize,
+    pos: usize
+}
+impl Iterator for Indices {
+    type Item = (usize, usize);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.pos >= self.rows * self.cols {
+            return None;
+        }
+        let index = (self.pos / self.cols, self.pos % self.cols);
+        self.pos += 1;
+        Some(index)
+    }
+}
+
+pub struct Iter<'a, T: Numerical<T>> {
+    data: &'a DenseData<T>,
+    indices: Indices
+}
+impl<'a, T: Numerical<T>> Iterator for Iter<'a, T> {
+    type Item = &'a T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let (i, j) = self.indices.next()?;
+        Some(&self.data[(i, j)])
+    }
+}
+
+pub struct IterIndexed<'a, T: Numerical<T>> {
+    data: &'a DenseData<T>,
+    indices: Indices
+}
+impl<'a, T: Numerical<T>> Iterator for IterIndexed<'a, T> {
+    type Item = (usize, usize, &'a T);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let (i, j) = self.indices.next()?;
+        Some((i, j, &self.data[(i, j)]))
+    }
+}
+
+pub struct Row<'a, T: Numerical<T>> {
+    data: &'a DenseData<T>,
+    row: usize,
+    col: usize
+}
+impl<'a, T: Numerical<T>> Iterator for Row<'a, T> {
+    type Item = &'a T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.col >= self.data.cols {
+            return None;
+        }
+        let value = &self.data[(self.row, self.col)];
+        self.col += 1;
+        Some(value)
+    }
+}
+
+pub struct Col<'a, T: Numerical<T>> {
+    data: &'a DenseData<T>,
+    row: usize,
+    col: usize
+}
+impl<'a, T: Numerical<T>> Iterator for Col<'a, T> {
+    type Item = &'a T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.row >= self.data.rows {
+            return None;
+        }
+        let value = &self.data[(self.row, self.col)];
+        self.row += 1;
+        Some(value)
+    }
+}
+
+impl<T: Numerical<T>> Dense<T> {
+    /// Row-major `(row, col)` index pairs over the logical shape.
+    pub fn indices(&self) -> Indices {
+        let data = self.data.as_ref().expect("Cannot iterate an error matrix");
+        Indices { rows: data.rows, cols: data.cols, pos: 0 }
+    }
+    /// Row-major iterator over element references.
+    pub fn iter(&self) -> Iter<'_, T> {
+        let data = self.data.as_ref().expect("Cannot iterate an error matrix");
+        Iter { data, indices: Indices { rows: data.rows, cols: data.cols, pos: 0 } }
+    }
+    /// Row-major iterator over `(row, col, &element)`.
+    pub fn iter_indexed(&self) -> IterIndexed<'_, T> {
+        let data = self.data.as_ref().expect("Cannot iterate an error matrix");
+        IterIndexed { data, indices: Indices { rows: data.rows, cols: data.cols, pos: 0 } }
+    }
+    /// Iterator over row `i`'s elements.
+    pub fn row(&self, i: usize) -> Row<'_, T> {
+        let data = self.data.as_ref().expect("Cannot iterate an error matrix");
+        assert!(i < data.rows, "Row index out of bounds");
+        Row { data, row: i, col: 0 }
+    }
+    /// Iterator over column `j`'s elements.
+    pub fn col(&self, j: usize) -> Col<'_, T> {
+        let data = self.data.as_ref().expect("Cannot iterate an error matrix");
+        assert!(j < data.cols, "Column index out of bounds");
+        Col { data, row: 0, col: j }
+    }
+    /// Borrows a strided window into this matrix without copying its elements.
+    pub fn view<U: AsIndex>(&self, rows: U, cols: U) -> DenseSlice<'_, T> {
+        let data = self.data.as_ref().expect("Cannot view an error matrix");
+        let ((row_start, row_end), (col_start, col_end)) = data.get_bounds(rows, cols);
+        let offset = data.offset(row_start, col_start);
+        let (row_stride, col_stride) = if data.transposed { (1, data.rows) } else { (data.cols, 1) };
+        DenseSlice {
+            rows: row_end - row_start,
+            cols: col_end - col_start,
+            row_stride,
+            col_stride,
+            values: unsafe {NonNull::new_unchecked(data.values.as_ptr().add(offset))},
+            _marker: PhantomData
+        }
+    }
+    /// Borrows a mutable, strided window into this matrix without copying its elements.
+    pub fn view_mut<U: AsIndex>(&mut self, rows: U, cols: U) -> DenseSliceMut<'_, T> {
+        let data = self.data.as_mut().expect("Cannot view an error matrix");
+        let ((row_start, row_end), (col_start, col_end)) = data.get_bounds(rows, cols);
+        let offset = data.offset(row_start, col_start);
+        let (row_stride, col_stride) = if data.transposed { (1, data.rows) } else { (data.cols, 1) };
+        DenseSliceMut {
+            rows: row_end - row_start,
+            cols: col_end - col_start,
+            row_stride,
+            col_stride,
+            values: unsafe {NonNull::new_unchecked(data.values.as_ptr().add(offset))},
+            _marker: PhantomData
+        }
+    }
+    /// Reinterprets the logical `rows`/`cols` of this matrix, as long as the
+    /// element count stays the same. A transposed matrix is materialized into
+    /// its logical row-major order first, since reinterpreting the raw buffer
+    /// directly would reshape the pre-transpose layout instead.
+    pub fn reshape(mut self, rows: usize, cols: usize) -> Self {
+        let Ok(data) = &mut self.data else {return self};
+        if rows * cols != data.rows * data.cols {
+            return Self::err(Error::InvalidDimensions((data.rows, data.cols), (rows, cols)));
+        }
+        if data.transposed {
+            let materialized = allocate::<T>(data.rows, data.cols);
+            for i in 0..data.rows {
+                for j in 0..data.cols {
+                    unsafe {materialized.as_ptr().add(i * data.cols + j).write(data[(i, j)])};
+                }
+            }
+            deallocate::<T>(data.values, data.rows, data.cols);
+            data.values = materialized;
+        }
+        data.rows = rows;
+        data.cols = cols;
+        data.transposed = false;
+        self
+    }
+}
+
+fn abs<T: Float<T>>(x: T) -> T {
+    if x < T::zero() { -x } else { x }
+}
+
+/// `(L, U, p, swaps)`: `p` is the row permutation applied to the source
+/// (`p[i]` is the source row now at position `i`), `swaps` is the number of
+/// row swaps performed, used by [`Dense::determinant`] to fix the sign.
+type LuDecomposition<T> = (Dense<T>, Dense<T>, Vec<usize>, usize);
+
+/// Doolittle LU decomposition with partial pivoting.
+fn lu_decompose<T: Float<T>>(source: &Dense<T>) -> Result<LuDecomposition<T>, Error> {
+    let data = match &source.data {
+        Ok(data) => data,
+        Err(err) => return Err(err.clone())
+    };
+    if data.rows != data.cols {
+        return Err(Error::InvalidDimensions((data.rows, data.cols), (data.cols, data.cols)));
+    }
+    let n = data.rows;
+
+    let mut u = Dense::new(n, n);
+    for i in 0..n {
+        for j in 0..n {
+            u[(i, j)] = data[(i, j)];
+        }
+    }
+    let mut l = Dense::new(n, n);
+    for i in 0..n {
+        l[(i, i)] = T::ident();
+    }
+    let mut p: Vec<usize> = (0..n).collect();
+    let mut swaps = 0;
+
+    for k in 0..n {
+        let mut pivot_row = k;
+        let mut pivot_val = abs(u[(k, k)]);
+        for i in (k + 1)..n {
+            let candidate = abs(u[(i, k)]);
+            if candidate > pivot_val {
+                pivot_row = i;
+                pivot_val = candidate;
+            }
+        }
+        // Pivot is negligible relative to 1 rather than exactly zero, so a
+        // near-singular matrix is caught too, not just an exactly-singular one.
+        if pivot_val + T::ident() == T::ident() {
+            return Err(Error::Singular);
+        }
+        if pivot_row != k {
+            for j in 0..n {
+                let tmp = u[(k, j)];
+                u[(k, j)] = u[(pivot_row, j)];
+                u[(pivot_row, j)] = tmp;
+            }
+            for j in 0..k {
+                let tmp = l[(k, j)];
+                l[(k, j)] = l[(pivot_row, j)];
+                l[(pivot_row, j)] = tmp;
+            }
+            p.swap(k, pivot_row);
+            swaps += 1;
+        }
+        for i in (k + 1)..n {
+            let m = u[(i, k)] / u[(k, k)];
+            l[(i, k)] = m;
+            for j in k..n {
+                u[(i, j)] = u[(i, j)] - m * u[(k, j)];
+            }
+        }
+    }
+
+    Ok((l, u, p, swaps))
+}
+
+impl<T: Float<T>> Dense<T> {
+    /// Determinant via LU decomposition: the product of `U`'s diagonal, sign-flipped once per row swap.
+    pub fn determinant(&self) -> Result<T, Error> {
+        let (_, u, _, swaps) = lu_decompose(self)?;
+        let n = u.data.as_ref().unwrap().rows;
+        let mut det = T::ident();
+        for i in 0..n {
+            det *= u[(i, i)];
+        }
+        if swaps % 2 == 1 {
+            det = -det;
+        }
+        Ok(det)
+    }
+
+    /// Solves `self * x = b` for `x` via forward/back substitution against the LU factors.
+    pub fn solve(&self, b: &Self) -> Result<Self, Error> {
+        let (l, u, p, _) = lu_decompose(self)?;
+        let b_data = match &b.data {
+            Ok(data) => data,
+            Err(err) => return Err(err.clone())
+        };
+        let n = p.len();
+        if b_data.rows != n {
+            return Err(Error::InvalidDimensions((n, n), (b_data.rows, b_data.cols)));
+        }
+        let cols = b_data.cols;
+
+        let mut y = Dense::new(n, cols);
+        for col in 0..cols {
+            for i in 0..n {
+                let mut sum = b[(p[i], col)];
+                for k in 0..i {
+                    sum = sum - l[(i, k)] * y[(k, col)];
+                }
+                y[(i, col)] = sum;
+            }
+        }
+
+        let mut x = Dense::new(n, cols);
+        for col in 0..cols {
+            for i in (0..n).rev() {
+                let mut sum = y[(i, col)];
+                for k in (i + 1)..n {
+                    sum = sum - u[(i, k)] * x[(k, col)];
+                }
+                x[(i, col)] = sum / u[(i, i)];
+            }
+        }
+
+        Ok(x)
+    }
+
+    /// Matrix inverse, solved as `self * X = I` one identity column at a time.
+    pub fn inverse(&self) -> Result<Self, Error> {
+        let data = match &self.data {
+            Ok(data) => data,
+            Err(err) => return Err(err.clone())
+        };
+        let n = data.rows;
+        if n != data.cols {
+            return Err(Error::InvalidDimensions((data.rows, data.cols), (data.cols, data.cols)));
+        }
+        let mut identity = Dense::new(n, n);
+        for i in 0..n {
+            identity[(i, i)] = T::ident();
+        }
+        self.solve(&identity)
+    }
+}
+
 #[macro_export]
 macro_rules! mat {
     ($rows:expr, $cols:expr; [$t:ty] = $($x:expr), +) => {
@@ -286,4 +841,208 @@ macro_rules! mat {
             result
         }
     };
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn filled(rows: usize, cols: usize) -> Dense<i32> {
+        let mut m = Dense::new(rows, cols);
+        let mut v = 1;
+        for i in 0..rows {
+            for j in 0..cols {
+                m[(i, j)] = v;
+                v += 1;
+            }
+        }
+        m
+    }
+
+    #[test]
+    fn view_reads_a_sub_block() {
+        let m = filled(3, 3);
+        let v = m.view(1..3, 0..2);
+        assert_eq!((v.rows, v.cols), (2, 2));
+        assert_eq!(v[(0, 0)], 4);
+        assert_eq!(v[(1, 1)], 8);
+    }
+
+    #[test]
+    fn view_mut_writes_back_into_the_parent() {
+        let mut m = filled(3, 3);
+        {
+            let mut v = m.view_mut(0..2, 1..3);
+            v[(0, 0)] = 100;
+        }
+        assert_eq!(m[(0, 1)], 100);
+    }
+
+    #[test]
+    fn view_over_a_transposed_matrix_reads_logical_elements() {
+        let m = filled(2, 3);
+        let t = m.t();
+        let v = t.view(.., ..);
+        assert_eq!((v.rows, v.cols), (3, 2));
+        assert_eq!(v[(0, 0)], 1);
+        assert_eq!(v[(0, 1)], 4);
+        assert_eq!(v[(1, 0)], 2);
+        assert_eq!(v[(2, 1)], 6);
+    }
+
+    #[test]
+    fn matmul_and_matadd_feed_through_views_without_materializing() {
+        let m = filled(2, 2);
+        let v1 = m.view(.., ..);
+        let v2 = m.view(.., ..);
+        let product = &v1 * &v2;
+        assert_eq!(product.iter().copied().collect::<Vec<_>>(), vec![7, 10, 15, 22]);
+        let sum = &v1 + &v2;
+        assert_eq!(sum.iter().copied().collect::<Vec<_>>(), vec![2, 4, 6, 8]);
+    }
+
+    #[test]
+    fn matmul_feeds_through_a_view_over_a_transposed_parent() {
+        let m = filled(2, 3);
+        let t = m.t();
+        let v = t.view(.., ..);
+        let w = m.view(.., ..);
+        let product = &v * &w;
+        assert_eq!((product.data.as_ref().unwrap().rows, product.data.as_ref().unwrap().cols), (3, 3));
+        assert_eq!(product.iter().copied().collect::<Vec<_>>(), vec![17, 22, 27, 22, 29, 36, 27, 36, 45]);
+    }
+
+    #[test]
+    fn reshape_of_a_transposed_matrix_keeps_the_logical_order() {
+        let m = filled(2, 3);
+        let reshaped = m.t().reshape(6, 1);
+        assert_eq!(reshaped.iter().copied().collect::<Vec<_>>(), vec![1, 4, 2, 5, 3, 6]);
+    }
+
+    fn square(values: [[f64; 2]; 2]) -> Dense<f64> {
+        let mut m = Dense::new(2, 2);
+        for i in 0..2 {
+            for j in 0..2 {
+                m[(i, j)] = values[i][j];
+            }
+        }
+        m
+    }
+
+    #[test]
+    fn determinant_of_a_known_matrix() {
+        let m = square([[4.0, 3.0], [6.0, 3.0]]);
+        assert_eq!(m.determinant().unwrap(), -6.0);
+    }
+
+    #[test]
+    fn determinant_flips_sign_once_per_row_swap() {
+        let m = square([[0.0, 1.0], [1.0, 0.0]]);
+        assert_eq!(m.determinant().unwrap(), -1.0);
+    }
+
+    #[test]
+    fn inverse_of_a_known_matrix_times_original_is_identity() {
+        let m = square([[4.0, 7.0], [2.0, 6.0]]);
+        let inv = m.inverse().unwrap();
+        let identity = m.dup() * inv;
+        assert!((identity[(0, 0)] - 1.0).abs() < 1e-9);
+        assert!(identity[(0, 1)].abs() < 1e-9);
+        assert!(identity[(1, 0)].abs() < 1e-9);
+        assert!((identity[(1, 1)] - 1.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn solve_recovers_x_in_ax_eq_b() {
+        let a = square([[2.0, 1.0], [1.0, 1.0]]);
+        let mut b = Dense::new(2, 1);
+        b[(0, 0)] = 3.0;
+        b[(1, 0)] = 2.0;
+        let x = a.solve(&b).unwrap();
+        assert!((x[(0, 0)] - 1.0).abs() < 1e-9);
+        assert!((x[(1, 0)] - 1.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn singular_matrix_is_rejected() {
+        let m = square([[1.0, 2.0], [2.0, 4.0]]);
+        assert!(matches!(m.determinant(), Err(Error::Singular)));
+        assert!(matches!(m.inverse(), Err(Error::Singular)));
+    }
+
+    #[test]
+    fn near_singular_matrix_is_rejected() {
+        let m = square([[1e-20, 0.0], [0.0, 1.0]]);
+        assert!(matches!(m.determinant(), Err(Error::Singular)));
+    }
+
+    #[test]
+    fn indices_and_iter_are_row_major() {
+        let m = filled(2, 3);
+        assert_eq!(m.indices().collect::<Vec<_>>(), vec![(0, 0), (0, 1), (0, 2), (1, 0), (1, 1), (1, 2)]);
+        assert_eq!(m.iter().copied().collect::<Vec<_>>(), vec![1, 2, 3, 4, 5, 6]);
+    }
+
+    #[test]
+    fn iter_indexed_pairs_positions_with_values() {
+        let m = filled(2, 2);
+        let pairs: Vec<_> = m.iter_indexed().map(|(i, j, v)| (i, j, *v)).collect();
+        assert_eq!(pairs, vec![(0, 0, 1), (0, 1, 2), (1, 0, 3), (1, 1, 4)]);
+    }
+
+    #[test]
+    fn row_and_col_iterate_a_single_line() {
+        let m = filled(2, 3);
+        assert_eq!(m.row(1).copied().collect::<Vec<_>>(), vec![4, 5, 6]);
+        assert_eq!(m.col(2).copied().collect::<Vec<_>>(), vec![3, 6]);
+    }
+
+    #[test]
+    fn iteration_over_a_transposed_matrix_follows_the_logical_shape() {
+        let t = filled(2, 3).t();
+        assert_eq!(t.indices().collect::<Vec<_>>(), vec![(0, 0), (0, 1), (1, 0), (1, 1), (2, 0), (2, 1)]);
+        assert_eq!(t.iter().copied().collect::<Vec<_>>(), vec![1, 4, 2, 5, 3, 6]);
+        assert_eq!(t.row(1).copied().collect::<Vec<_>>(), vec![2, 5]);
+        assert_eq!(t.col(1).copied().collect::<Vec<_>>(), vec![4, 5, 6]);
+    }
+
+    #[test]
+    fn neg_flips_every_element() {
+        let m = -filled(1, 3);
+        assert_eq!(m.iter().copied().collect::<Vec<_>>(), vec![-1, -2, -3]);
+    }
+
+    #[test]
+    fn div_scales_every_element() {
+        let m = filled(1, 3).div_scalar(2);
+        assert_eq!(m.iter().copied().collect::<Vec<_>>(), vec![0, 1, 1]);
+    }
+
+    #[test]
+    fn add_assign_adds_element_wise() {
+        let mut a = filled(1, 3);
+        a += filled(1, 3);
+        assert_eq!(a.iter().copied().collect::<Vec<_>>(), vec![2, 4, 6]);
+    }
+
+    #[test]
+    fn sub_assign_subtracts_element_wise() {
+        let mut a = filled(1, 3);
+        a -= filled(1, 3);
+        assert_eq!(a.iter().copied().collect::<Vec<_>>(), vec![0, 0, 0]);
+    }
+
+    #[test]
+    fn mul_assign_matmuls_by_default() {
+        let mut a = filled(2, 2);
+        a *= filled(2, 2);
+        assert_eq!(a.iter().copied().collect::<Vec<_>>(), vec![7, 10, 15, 22]);
+    }
+
+    #[test]
+    fn mul_assign_is_pointwise_once_flagged() {
+        let mut a = filled(2, 2).p();
+        a *= filled(2, 2).p();
+        assert_eq!(a.iter().copied().collect::<Vec<_>>(), vec![1, 4, 9, 16]);
+    }
 }
\ No newline at end of file